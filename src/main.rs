@@ -1,6 +1,10 @@
 use eyre::{Result, eyre};
 use num_traits::{Float, Num, NumCast, Signed};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Trait alias for numeric types that can be used in `Vec2`.
 pub trait Scalar: Num + NumCast + Copy {}
@@ -10,8 +14,96 @@ impl<T: Num + NumCast + Copy> Scalar for T {}
 pub trait FloatCast: Float + NumCast {}
 impl<T: Float + NumCast> FloatCast for T {}
 
-/// Trait for 2D vector-like types.
-trait Vec2Like<T: Scalar> {
+/// Approximate equality, for types where `PartialEq` is unreliable because
+/// the value went through a floating-point computation (e.g. `normalize`).
+pub trait ApproxEq<Eps = Self> {
+    /// A sensible default epsilon for this type.
+    fn approx_epsilon() -> Eps;
+
+    /// Whether `self` and `other` are equal within [`Self::approx_epsilon`].
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::approx_epsilon())
+    }
+
+    /// Whether `self` and `other` are equal within `eps`.
+    fn approx_eq_eps(&self, other: &Self, eps: &Eps) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn approx_epsilon() -> f32 {
+        1.0e-6
+    }
+
+    fn approx_eq_eps(&self, other: &f32, eps: &f32) -> bool {
+        (self - other).abs() < *eps
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_epsilon() -> f64 {
+        1.0e-12
+    }
+
+    fn approx_eq_eps(&self, other: &f64, eps: &f64) -> bool {
+        (self - other).abs() < *eps
+    }
+}
+
+#[cfg(test)]
+mod approx_eq_tests {
+    use super::*;
+
+    #[test]
+    fn normalize_round_trip_is_unit_length() {
+        let v = Vec2::<f64>::new(3.0, 4.0);
+        let unit = v.normalize::<f64>().unwrap();
+
+        assert!(unit.length::<f64>().approx_eq(&1.0));
+    }
+
+    #[test]
+    fn normalize_preserves_direction() {
+        let v = Vec2::<f64>::new(3.0, 4.0);
+        let unit = v.normalize::<f64>().unwrap();
+        let expected = UnitVec2::<f64>::new_unchecked(0.6, 0.8);
+
+        assert!(unit.approx_eq(&expected));
+    }
+
+    #[test]
+    fn normalize_zero_length_errors() {
+        let v = Vec2::<f64>::new(0.0, 0.0);
+
+        assert!(v.normalize::<f64>().is_err());
+    }
+
+    #[test]
+    fn rotate_by_full_turn_is_identity() {
+        let unit = UnitVec2::<f64>::from_angle(Angle::degrees(30.0));
+        let rotated = unit.rotate(Angle::degrees(360.0));
+
+        assert!(rotated.approx_eq(&unit));
+    }
+
+    #[test]
+    fn rotate_by_quarter_turn_matches_from_angle() {
+        let start = UnitVec2::<f64>::from_angle(Angle::degrees(0.0));
+        let rotated = start.rotate(Angle::degrees(90.0));
+        let expected = UnitVec2::<f64>::from_angle(Angle::degrees(90.0));
+
+        assert!(rotated.approx_eq(&expected));
+    }
+}
+
+/// Marker type for an unspecified coordinate space.
+///
+/// Used as the default `U` parameter on [`Vec2`] and [`UnitVec2`] so existing
+/// code that doesn't care about spaces keeps working unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnknownUnit;
+
+/// Trait for 2D vector-like types tagged with a coordinate space `U`.
+trait Vec2Like<T: Scalar, U = UnknownUnit> {
     fn x(&self) -> T;
     fn y(&self) -> T;
 
@@ -23,8 +115,8 @@ trait Vec2Like<T: Scalar> {
         (x * x + y * y).sqrt()
     }
 
-    /// Euclidean distance between `self` and another vector.
-    fn distance<F: FloatCast>(&self, other: &impl Vec2Like<T>) -> F {
+    /// Euclidean distance between `self` and another vector in the same space.
+    fn distance<F: FloatCast>(&self, other: &impl Vec2Like<T, U>) -> F {
         let dx = F::from(other.x() - self.x()).expect("cast failed");
         let dy = F::from(other.y() - self.y()).expect("cast failed");
 
@@ -32,7 +124,7 @@ trait Vec2Like<T: Scalar> {
     }
 
     /// Absolute value per component (only for signed types).
-    fn abs(&self) -> Vec2<T>
+    fn abs(&self) -> Vec2<T, U>
     where
         T: Signed,
     {
@@ -40,14 +132,38 @@ trait Vec2Like<T: Scalar> {
     }
 }
 
-/// A generic 2D vector type.
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct Vec2<T: Scalar> {
+/// A generic 2D vector type, tagged with a coordinate space `U`.
+///
+/// `U` defaults to [`UnknownUnit`] and carries no runtime cost (it's a
+/// zero-sized [`PhantomData`]); it only exists so the type checker can stop
+/// you from adding a `Vec2<T, WorldSpace>` to a `Vec2<T, ScreenSpace>`.
+struct Vec2<T: Scalar, U = UnknownUnit> {
     x: T,
     y: T,
+    _unit: PhantomData<U>,
 }
 
-impl<T: Scalar> Vec2Like<T> for Vec2<T> {
+impl<T: Scalar + Debug, U> Debug for Vec2<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Vec2").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+impl<T: Scalar, U> Clone for Vec2<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Scalar, U> Copy for Vec2<T, U> {}
+
+impl<T: Scalar, U> PartialEq for Vec2<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<T: Scalar, U> Vec2Like<T, U> for Vec2<T, U> {
     fn x(&self) -> T {
         self.x
     }
@@ -57,13 +173,17 @@ impl<T: Scalar> Vec2Like<T> for Vec2<T> {
     }
 }
 
-impl<T: Scalar> Vec2<T> {
+impl<T: Scalar, U> Vec2<T, U> {
     fn new(x: T, y: T) -> Self {
-        Self { x, y }
+        Self {
+            x,
+            y,
+            _unit: PhantomData,
+        }
     }
 
     /// Normalize into a `UnitVec2`, erroring if zero-length.
-    fn normalize<F: FloatCast>(self) -> Result<UnitVec2<F>> {
+    fn normalize<F: FloatCast>(self) -> Result<UnitVec2<F, U>> {
         let len = self.length::<F>();
         if len == F::zero() {
             return Err(eyre!("Cannot normalize a zero-length vector"));
@@ -72,39 +192,295 @@ impl<T: Scalar> Vec2<T> {
         let x = F::from(self.x).expect("cast failed");
         let y = F::from(self.y).expect("cast failed");
 
-        Ok(UnitVec2 {
-            x: x / len,
-            y: y / len,
-        })
+        Ok(UnitVec2::new_unchecked(x / len, y / len))
+    }
+
+    /// Dot product: `x1*x2 + y1*y2`.
+    fn dot(&self, other: &Vec2<T, U>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// 2D "cross product" (the scalar z-component of the 3D cross product):
+    /// `x1*y2 - y1*x2`.
+    fn cross(&self, other: &Vec2<T, U>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Linearly interpolate towards `other` by `t`, casting through `F`.
+    fn lerp<F: FloatCast>(&self, other: &Vec2<T, U>, t: F) -> Vec2<F, U> {
+        let a = Vec2::new(
+            F::from(self.x).expect("cast failed"),
+            F::from(self.y).expect("cast failed"),
+        );
+        let b = Vec2::new(
+            F::from(other.x).expect("cast failed"),
+            F::from(other.y).expect("cast failed"),
+        );
+
+        a + (b - a) * t
+    }
+
+    /// Reinterpret this vector as belonging to a different coordinate space,
+    /// without changing its components. Use this at deliberate conversion
+    /// boundaries (e.g. after applying a transform between spaces).
+    fn cast_unit<V>(self) -> Vec2<T, V> {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+impl<T: Scalar, U> Add for Vec2<T, U> {
+    type Output = Vec2<T, U>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Scalar, U> Sub for Vec2<T, U> {
+    type Output = Vec2<T, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Scalar + Signed, U> Neg for Vec2<T, U> {
+    type Output = Vec2<T, U>;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Scalar, U> Mul<T> for Vec2<T, U> {
+    type Output = Vec2<T, U>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Vec2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Scalar, U> Div<T> for Vec2<T, U> {
+    type Output = Vec2<T, U>;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Vec2::new(self.x / scalar, self.y / scalar)
     }
 }
 
-impl<T: Scalar> From<(T, T)> for Vec2<T> {
+impl<F: FloatCast, U> Vec2<F, U> {
+    /// Reflect `self` off a surface with unit normal `n`: `v - 2*(v·n)*n`.
+    fn reflect(&self, n: UnitVec2<F, U>) -> Vec2<F, U> {
+        let n = n.as_vec2();
+        let two = F::from(2).expect("cast failed");
+
+        *self - n * (two * self.dot(&n))
+    }
+
+    /// Project `self` onto `axis`, returning the component of `self` parallel to it.
+    fn project_onto(&self, axis: UnitVec2<F, U>) -> Vec2<F, U> {
+        let axis = axis.as_vec2();
+
+        axis * self.dot(&axis)
+    }
+
+    /// The angle this vector makes with the positive x-axis: `atan2(y, x)`.
+    fn angle_from_x_axis(&self) -> Angle<F> {
+        Angle::radians(self.y.atan2(self.x))
+    }
+
+    /// The signed angle to rotate `self` onto `other`, via `atan2(cross, dot)`.
+    fn angle_to(&self, other: &Vec2<F, U>) -> Angle<F> {
+        Angle::radians(self.cross(other).atan2(self.dot(other)))
+    }
+
+    /// Rotate `self` by `angle`.
+    fn rotate(self, angle: Angle<F>) -> Self {
+        let (sin, cos) = (angle.radians.sin(), angle.radians.cos());
+
+        Vec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+#[cfg(test)]
+mod vec2_arithmetic_tests {
+    use super::*;
+
+    #[test]
+    fn add_sub_neg() {
+        let a = Vec2::<i32>::new(1, 2);
+        let b = Vec2::<i32>::new(3, 4);
+
+        assert_eq!(a + b, Vec2::new(4, 6));
+        assert_eq!(b - a, Vec2::new(2, 2));
+        assert_eq!(-a, Vec2::new(-1, -2));
+    }
+
+    #[test]
+    fn mul_div_scalar() {
+        let a = Vec2::<i32>::new(2, 4);
+
+        assert_eq!(a * 3, Vec2::new(6, 12));
+        assert_eq!(a / 2, Vec2::new(1, 2));
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let a = Vec2::<i32>::new(1, 2);
+        let b = Vec2::<i32>::new(3, 4);
+
+        assert_eq!(a.dot(&b), 11);
+        assert_eq!(a.cross(&b), -2);
+    }
+
+    #[test]
+    fn lerp_halfway() {
+        let a = Vec2::<f64>::new(0.0, 0.0);
+        let b = Vec2::<f64>::new(2.0, 4.0);
+
+        assert_eq!(a.lerp(&b, 0.5_f64), Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn reflect_off_horizontal_surface() {
+        let v = Vec2::<f64>::new(1.0, -1.0);
+        let n = UnitVec2::<f64>::new(0.0, 1.0).unwrap();
+
+        assert_eq!(v.reflect(n), Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn project_onto_axis() {
+        let v = Vec2::<f64>::new(3.0, 4.0);
+        let axis = UnitVec2::<f64>::new(1.0, 0.0).unwrap();
+
+        assert_eq!(v.project_onto(axis), Vec2::new(3.0, 0.0));
+    }
+}
+
+#[cfg(test)]
+mod angle_tests {
+    use super::*;
+
+    #[test]
+    fn angle_from_x_axis_matches_atan2() {
+        let v = Vec2::<f64>::new(1.0, 1.0);
+        let angle = v.angle_from_x_axis();
+
+        assert!((angle.as_radians() - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn angle_to_is_signed() {
+        let a = Vec2::<f64>::new(1.0, 0.0);
+        let b = Vec2::<f64>::new(0.0, 1.0);
+
+        let angle = a.angle_to(&b);
+
+        assert!((angle.as_radians() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+}
+
+impl<F: FloatCast + ApproxEq<F>, U> ApproxEq<F> for Vec2<F, U> {
+    fn approx_epsilon() -> F {
+        F::approx_epsilon()
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: &F) -> bool {
+        self.x.approx_eq_eps(&other.x, eps) && self.y.approx_eq_eps(&other.y, eps)
+    }
+}
+
+impl<T: Scalar, U> From<(T, T)> for Vec2<T, U> {
     fn from((x, y): (T, T)) -> Self {
         Vec2::new(x, y)
     }
 }
 
-impl<T: Scalar> From<[T; 2]> for Vec2<T> {
+impl<T: Scalar, U> From<[T; 2]> for Vec2<T, U> {
     fn from([x, y]: [T; 2]) -> Self {
         Vec2::new(x, y)
     }
 }
 
-impl<T: Scalar> Into<(T, T)> for Vec2<T> {
+impl<T: Scalar, U> Into<(T, T)> for Vec2<T, U> {
     fn into(self) -> (T, T) {
         (self.x, self.y)
     }
 }
 
-/// A 2D unit vector (always length = 1).
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct UnitVec2<T: Scalar> {
+#[cfg(feature = "serde")]
+impl<T: Scalar + Serialize, U> Serialize for Vec2<T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Scalar + Deserialize<'de>, U> Deserialize<'de> for Vec2<T, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = <(T, T)>::deserialize(deserializer)?;
+
+        Ok(Vec2::new(x, y))
+    }
+}
+
+/// A 2D unit vector (always length = 1), tagged with a coordinate space `U`.
+struct UnitVec2<T: Scalar, U = UnknownUnit> {
     x: T,
     y: T,
+    _unit: PhantomData<U>,
+}
+
+impl<T: Scalar + Debug, U> Debug for UnitVec2<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnitVec2").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+impl<T: Scalar, U> Clone for UnitVec2<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<T: Scalar> Vec2Like<T> for UnitVec2<T> {
+impl<T: Scalar, U> Copy for UnitVec2<T, U> {}
+
+impl<T: Scalar, U> PartialEq for UnitVec2<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+/// An angle, stored internally in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Angle<F: FloatCast> {
+    radians: F,
+}
+
+impl<F: FloatCast> Angle<F> {
+    /// Construct an angle from a value in radians.
+    fn radians(radians: F) -> Self {
+        Self { radians }
+    }
+
+    /// Construct an angle from a value in degrees.
+    fn degrees(degrees: F) -> Self {
+        let pi = F::from(std::f64::consts::PI).expect("cast failed");
+        let half_turn = F::from(180).expect("cast failed");
+
+        Self {
+            radians: degrees * pi / half_turn,
+        }
+    }
+
+    /// The angle's value in radians.
+    fn as_radians(&self) -> F {
+        self.radians
+    }
+}
+
+impl<T: Scalar, U> Vec2Like<T, U> for UnitVec2<T, U> {
     fn x(&self) -> T {
         self.x
     }
@@ -114,28 +490,556 @@ impl<T: Scalar> Vec2Like<T> for UnitVec2<T> {
     }
 }
 
-impl<F: FloatCast> UnitVec2<F> {
+impl<F: FloatCast, U> UnitVec2<F, U> {
     fn new<T: Scalar>(x: T, y: T) -> Result<Self> {
         Vec2::new(x, y).normalize::<F>()
     }
 
-    fn from_vec2<T: Scalar>(v: Vec2<T>) -> Result<Self> {
+    /// Build a `UnitVec2` directly from already-normalized components,
+    /// skipping the zero-length check. Only call this with components that
+    /// are known to already have unit length.
+    fn new_unchecked(x: F, y: F) -> Self {
+        Self {
+            x,
+            y,
+            _unit: PhantomData,
+        }
+    }
+
+    fn from_vec2<T: Scalar>(v: Vec2<T, U>) -> Result<Self> {
         v.normalize::<F>()
     }
 
-    fn as_vec2(self) -> Vec2<F> {
+    fn as_vec2(self) -> Vec2<F, U> {
         Vec2::new(self.x, self.y)
     }
+
+    /// Reinterpret this unit vector as belonging to a different coordinate
+    /// space, without changing its components.
+    fn cast_unit<V>(self) -> UnitVec2<F, V> {
+        UnitVec2::new_unchecked(self.x, self.y)
+    }
+
+    /// Build the unit vector pointing at `angle` from the positive x-axis:
+    /// `(cos θ, sin θ)`.
+    fn from_angle(angle: Angle<F>) -> Self {
+        UnitVec2::new_unchecked(angle.radians.cos(), angle.radians.sin())
+    }
+
+    /// Rotate this unit vector by `angle`.
+    fn rotate(self, angle: Angle<F>) -> Self {
+        let rotated = self.as_vec2().rotate(angle);
+
+        UnitVec2::new_unchecked(rotated.x, rotated.y)
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct Player<T: Scalar, F: FloatCast> {
-    position: Vec2<T>,
-    direction: UnitVec2<F>,
+impl<F: FloatCast + ApproxEq<F>, U> ApproxEq<F> for UnitVec2<F, U> {
+    fn approx_epsilon() -> F {
+        F::approx_epsilon()
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: &F) -> bool {
+        self.x.approx_eq_eps(&other.x, eps) && self.y.approx_eq_eps(&other.y, eps)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F: FloatCast + Serialize, U> Serialize for UnitVec2<F, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: FloatCast + Deserialize<'de>, U> Deserialize<'de> for UnitVec2<F, U> {
+    /// Deserializes `[x, y]` and re-normalizes, so an invalid on-disk value
+    /// (not actually unit length) can't produce a non-unit `UnitVec2`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = <(F, F)>::deserialize(deserializer)?;
+
+        Vec2::new(x, y).normalize::<F>().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn vec2_round_trips_through_json() {
+        let v = Vec2::<f64>::new(3.0, 4.0);
+        let json = serde_json::to_string(&v).unwrap();
+
+        assert_eq!(serde_json::from_str::<Vec2<f64>>(&json).unwrap(), v);
+    }
+
+    #[test]
+    fn unit_vec2_round_trips_through_json() {
+        let unit = UnitVec2::<f64>::new(3.0, 4.0).unwrap();
+        let json = serde_json::to_string(&unit).unwrap();
+
+        assert_eq!(serde_json::from_str::<UnitVec2<f64>>(&json).unwrap(), unit);
+    }
+
+    #[test]
+    fn unit_vec2_deserialize_renormalizes_non_unit_input() {
+        let non_unit: UnitVec2<f64> = serde_json::from_str("[3.0, 4.0]").unwrap();
+
+        assert!(non_unit.approx_eq(&UnitVec2::new_unchecked(0.6, 0.8)));
+    }
+
+    #[test]
+    fn unit_vec2_deserialize_errors_on_zero_length() {
+        let result: std::result::Result<UnitVec2<f64>, _> = serde_json::from_str("[0.0, 0.0]");
+
+        assert!(result.is_err());
+    }
+}
+
+/// A 2D affine transform, tagged with a coordinate space `U`.
+///
+/// Stored as the 2x3 matrix
+/// ```text
+/// | m11 m12 |
+/// | m21 m22 |
+/// | m31 m32 |
+/// ```
+/// and applied to row vectors as `v' = v * M`.
+struct Transform2D<F: FloatCast, U = UnknownUnit> {
+    m11: F,
+    m12: F,
+    m21: F,
+    m22: F,
+    m31: F,
+    m32: F,
+    _unit: PhantomData<U>,
+}
+
+impl<F: FloatCast + Debug, U> Debug for Transform2D<F, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transform2D")
+            .field("m11", &self.m11)
+            .field("m12", &self.m12)
+            .field("m21", &self.m21)
+            .field("m22", &self.m22)
+            .field("m31", &self.m31)
+            .field("m32", &self.m32)
+            .finish()
+    }
 }
 
-impl<T: Scalar, F: FloatCast> Player<T, F> {
-    fn new(position: Vec2<T>, direction: UnitVec2<F>) -> Self {
+impl<F: FloatCast, U> Clone for Transform2D<F, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: FloatCast, U> Copy for Transform2D<F, U> {}
+
+impl<F: FloatCast, U> PartialEq for Transform2D<F, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.m11 == other.m11
+            && self.m12 == other.m12
+            && self.m21 == other.m21
+            && self.m22 == other.m22
+            && self.m31 == other.m31
+            && self.m32 == other.m32
+    }
+}
+
+impl<F: FloatCast, U> Transform2D<F, U> {
+    fn new(m11: F, m12: F, m21: F, m22: F, m31: F, m32: F) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+            _unit: PhantomData,
+        }
+    }
+
+    /// The transform that leaves every vector unchanged.
+    fn identity() -> Self {
+        Self::new(F::one(), F::zero(), F::zero(), F::one(), F::zero(), F::zero())
+    }
+
+    /// A pure translation by `(x, y)`.
+    fn translation(x: F, y: F) -> Self {
+        Self::new(F::one(), F::zero(), F::zero(), F::one(), x, y)
+    }
+
+    /// A pure scale by `(x, y)`.
+    fn scale(x: F, y: F) -> Self {
+        Self::new(x, F::zero(), F::zero(), y, F::zero(), F::zero())
+    }
+
+    /// A pure rotation by `angle`.
+    fn rotation(angle: Angle<F>) -> Self {
+        let (sin, cos) = (angle.radians.sin(), angle.radians.cos());
+
+        Self::new(cos, sin, -sin, cos, F::zero(), F::zero())
+    }
+
+    /// Compose `self` with `other`, applying `self` first and `other` second.
+    fn then(&self, other: &Transform2D<F, U>) -> Transform2D<F, U> {
+        Transform2D::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+
+    /// Apply the linear part of this transform to a direction vector,
+    /// ignoring translation.
+    fn transform_vector(&self, v: Vec2<F, U>) -> Vec2<F, U> {
+        Vec2::new(
+            v.x * self.m11 + v.y * self.m21,
+            v.x * self.m12 + v.y * self.m22,
+        )
+    }
+
+    /// Apply the full affine transform (including translation) to a point.
+    fn transform_point(&self, p: Point2D<F, U>) -> Point2D<F, U> {
+        Point2D::new(
+            p.x * self.m11 + p.y * self.m21 + self.m31,
+            p.x * self.m12 + p.y * self.m22 + self.m32,
+        )
+    }
+}
+
+impl<F: FloatCast, U> Mul for Transform2D<F, U> {
+    type Output = Transform2D<F, U>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.then(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod transform2d_tests {
+    use super::*;
+
+    #[test]
+    fn identity_leaves_vectors_and_points_unchanged() {
+        let t = Transform2D::<f64>::identity();
+        let v = Vec2::new(3.0, 4.0);
+        let p = Point2D::new(3.0, 4.0);
+
+        assert_eq!(t.transform_vector(v), v);
+        assert_eq!(t.transform_point(p), p);
+    }
+
+    #[test]
+    fn translation_moves_points_but_not_vectors() {
+        let t = Transform2D::<f64>::translation(1.0, 2.0);
+        let v = Vec2::new(3.0, 4.0);
+        let p = Point2D::new(3.0, 4.0);
+
+        assert_eq!(t.transform_point(p), Point2D::new(4.0, 6.0));
+        assert_eq!(t.transform_vector(v), v);
+    }
+
+    #[test]
+    fn scale_scales_both_vectors_and_points() {
+        let t = Transform2D::<f64>::scale(2.0, 3.0);
+        let v = Vec2::new(1.0, 1.0);
+        let p = Point2D::new(1.0, 1.0);
+
+        assert_eq!(t.transform_point(p), Point2D::new(2.0, 3.0));
+        assert_eq!(t.transform_vector(v), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn rotation_matches_vec2_rotate() {
+        let angle = Angle::degrees(90.0_f64);
+        let t = Transform2D::<f64>::rotation(angle);
+        let v = Vec2::new(1.0, 0.0);
+
+        assert!(t.transform_vector(v).approx_eq(&v.rotate(angle)));
+    }
+
+    #[test]
+    fn then_composes_in_application_order() {
+        let translate = Transform2D::<f64>::translation(5.0, 0.0);
+        let rotate = Transform2D::<f64>::rotation(Angle::degrees(90.0));
+        let combined = translate.then(&rotate);
+
+        let p = Point2D::new(1.0, 0.0);
+        let expected = rotate.transform_point(translate.transform_point(p));
+
+        assert!(combined.transform_point(p).approx_eq(&expected));
+    }
+}
+
+/// A point in affine space, tagged with a coordinate space `U`.
+///
+/// Unlike [`Vec2`], a `Point2D` has no free-vector arithmetic: two points
+/// can't be added (what would that mean geometrically?), but their
+/// difference is a displacement `Vec2`, and a point offset by a `Vec2` is
+/// another point.
+struct Point2D<T: Scalar, U = UnknownUnit> {
+    x: T,
+    y: T,
+    _unit: PhantomData<U>,
+}
+
+impl<T: Scalar + Debug, U> Debug for Point2D<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Point2D").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+impl<T: Scalar, U> Clone for Point2D<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Scalar, U> Copy for Point2D<T, U> {}
+
+impl<T: Scalar, U> PartialEq for Point2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<F: FloatCast + ApproxEq<F>, U> ApproxEq<F> for Point2D<F, U> {
+    fn approx_epsilon() -> F {
+        F::approx_epsilon()
+    }
+
+    fn approx_eq_eps(&self, other: &Self, eps: &F) -> bool {
+        self.x.approx_eq_eps(&other.x, eps) && self.y.approx_eq_eps(&other.y, eps)
+    }
+}
+
+impl<T: Scalar, U> Point2D<T, U> {
+    fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            _unit: PhantomData,
+        }
+    }
+
+    fn to_vec2(self) -> Vec2<T, U> {
+        Vec2::new(self.x, self.y)
+    }
+
+    fn from_vec2(v: Vec2<T, U>) -> Self {
+        Self::new(v.x, v.y)
+    }
+
+    /// Distance between two points.
+    fn distance<F: FloatCast>(&self, other: &Point2D<T, U>) -> F {
+        (*other - *self).length()
+    }
+
+    /// Reinterpret this point as belonging to a different coordinate space,
+    /// without changing its components.
+    fn cast_unit<V>(self) -> Point2D<T, V> {
+        Point2D::new(self.x, self.y)
+    }
+}
+
+impl<T: Scalar, U> Sub for Point2D<T, U> {
+    type Output = Vec2<T, U>;
+
+    /// The displacement from `rhs` to `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Scalar, U> Add<Vec2<T, U>> for Point2D<T, U> {
+    type Output = Point2D<T, U>;
+
+    fn add(self, rhs: Vec2<T, U>) -> Self::Output {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Scalar, U> Sub<Vec2<T, U>> for Point2D<T, U> {
+    type Output = Point2D<T, U>;
+
+    fn sub(self, rhs: Vec2<T, U>) -> Self::Output {
+        Point2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Scalar, U> From<(T, T)> for Point2D<T, U> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2D::new(x, y)
+    }
+}
+
+impl<T: Scalar, U> From<[T; 2]> for Point2D<T, U> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point2D::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod point2d_tests {
+    use super::*;
+
+    #[test]
+    fn point_plus_vec_is_point() {
+        let p = Point2D::<f64>::new(1.0, 2.0);
+        let v = Vec2::new(3.0, 4.0);
+
+        assert_eq!(p + v, Point2D::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn point_minus_point_is_vec() {
+        let a = Point2D::<f64>::new(5.0, 7.0);
+        let b = Point2D::new(1.0, 2.0);
+
+        assert_eq!(a - b, Vec2::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn point_minus_vec_is_point() {
+        let p = Point2D::<f64>::new(5.0, 7.0);
+        let v = Vec2::new(1.0, 2.0);
+
+        assert_eq!(p - v, Point2D::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn distance_matches_displacement_length() {
+        let a = Point2D::<f64>::new(0.0, 0.0);
+        let b = Point2D::new(3.0, 4.0);
+
+        assert_eq!(a.distance::<f64>(&b), 5.0);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar + Serialize, U> Serialize for Point2D<T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Scalar + Deserialize<'de>, U> Deserialize<'de> for Point2D<T, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = <(T, T)>::deserialize(deserializer)?;
+
+        Ok(Point2D::new(x, y))
+    }
+}
+
+/// A 2D size (width/height), tagged with a coordinate space `U`.
+struct Size2D<T: Scalar, U = UnknownUnit> {
+    width: T,
+    height: T,
+    _unit: PhantomData<U>,
+}
+
+impl<T: Scalar + Debug, U> Debug for Size2D<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Size2D")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+impl<T: Scalar, U> Clone for Size2D<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Scalar, U> Copy for Size2D<T, U> {}
+
+impl<T: Scalar, U> PartialEq for Size2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height
+    }
+}
+
+impl<T: Scalar, U> Size2D<T, U> {
+    fn new(width: T, height: T) -> Self {
+        Self {
+            width,
+            height,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Reinterpret this size as belonging to a different coordinate space,
+    /// without changing its components.
+    fn cast_unit<V>(self) -> Size2D<T, V> {
+        Size2D::new(self.width, self.height)
+    }
+}
+
+impl<T: Scalar, U> From<Vec2<T, U>> for Size2D<T, U> {
+    fn from(v: Vec2<T, U>) -> Self {
+        Size2D::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar, U> From<Size2D<T, U>> for Vec2<T, U> {
+    fn from(s: Size2D<T, U>) -> Self {
+        Vec2::new(s.width, s.height)
+    }
+}
+
+#[cfg(test)]
+mod size2d_tests {
+    use super::*;
+
+    #[test]
+    fn vec2_round_trips_through_size2d() {
+        let v = Vec2::new(3.0, 4.0);
+        let size: Size2D<f64> = v.into();
+
+        assert_eq!(size, Size2D::new(3.0, 4.0));
+        assert_eq!(Vec2::from(size), v);
+    }
+}
+
+struct Player<T: Scalar, F: FloatCast, U = UnknownUnit> {
+    position: Point2D<T, U>,
+    direction: UnitVec2<F, U>,
+}
+
+impl<T: Scalar + Debug, F: FloatCast + Debug, U> Debug for Player<T, F, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Player")
+            .field("position", &self.position)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+impl<T: Scalar, F: FloatCast, U> Clone for Player<T, F, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Scalar, F: FloatCast, U> Copy for Player<T, F, U> {}
+
+impl<T: Scalar, F: FloatCast, U> PartialEq for Player<T, F, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.direction == other.direction
+    }
+}
+
+impl<T: Scalar, F: FloatCast, U> Player<T, F, U> {
+    fn new(position: Point2D<T, U>, direction: UnitVec2<F, U>) -> Self {
         Self {
             position,
             direction,
@@ -143,6 +1047,24 @@ impl<T: Scalar, F: FloatCast> Player<T, F> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: Scalar + Serialize, F: FloatCast + Serialize, U> Serialize for Player<T, F, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.position, self.direction).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Scalar + Deserialize<'de>, F: FloatCast + Deserialize<'de>, U> Deserialize<'de>
+    for Player<T, F, U>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (position, direction) = <(Point2D<T, U>, UnitVec2<F, U>)>::deserialize(deserializer)?;
+
+        Ok(Player::new(position, direction))
+    }
+}
+
 fn main() -> Result<()> {
     let player_i8 = Player::new((3i8, 4i8).into(), UnitVec2::<f32>::new(1i8, 0i8)?);
     println!("i8 player: {:?}", player_i8);